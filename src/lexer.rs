@@ -0,0 +1,474 @@
+use self::cursor::{cursor, Cursor};
+
+mod cursor;
+
+#[derive(Clone, Copy, Debug)]
+struct Char {
+    line_num: usize,
+    row_num: usize,
+    c: char,
+}
+
+#[derive(Debug)]
+pub struct Token {
+    pub line_num: usize,
+    pub row_num: usize,
+    pub token_kind: TokenKind,
+}
+
+#[derive(Debug)]
+pub enum ReservedKind {
+    Type,
+    Enum,
+    Logic,
+}
+
+fn detect_reserved(word: &str) -> Option<ReservedKind> {
+    match word {
+        "type" => Some(ReservedKind::Type),
+        "enum" => Some(ReservedKind::Enum),
+        "logic" => Some(ReservedKind::Logic),
+        _ => None,
+    }
+}
+
+/// Whether a comment was written as a `// line` or a `/* block */`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommentShape {
+    Line,
+    Block,
+}
+
+/// Where a doc comment attaches: `///`/`/**` document the following item
+/// (`Outer`), while `//!`/`/*!` document the enclosing item (`Inner`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocPlacement {
+    Outer,
+    Inner,
+}
+
+#[derive(Debug)]
+pub enum TokenKind {
+    /// example) "// comment", "/* comment */", "/// doc", "//! doc"
+    Comment {
+        shape: CommentShape,
+        doc: Option<DocPlacement>,
+    },
+
+    /// '\t', '\n', '\r', ' '
+    Whitespace,
+
+    /// example) "Bus"
+    Ident(String),
+
+    /// "type", "enum", "logic", ...
+    Reserved(ReservedKind),
+
+    /// example) "8", "1_000"
+    Number(u32),
+
+    /// example) "\"_@?\"", "8'hFF", "8'd255"
+    ///
+    /// `mask` marks which bits of `value` are actually known: a `1` bit
+    /// means the corresponding bit of `value` is meaningful, a `0` bit
+    /// means it's a don't-care (from a `?` in a quoted literal). Literals
+    /// with no don't-care bits have `mask == (1 << bitwidth) - 1`.
+    Literal {
+        bitwidth: u32,
+        value: u32,
+        mask: u32,
+    },
+
+    // One-char tokens:
+    /// ";"
+    Semi,
+    /// ","
+    Comma,
+    /// "."
+    Dot,
+    /// "("
+    OpenParen,
+    /// ")"
+    CloseParen,
+    /// "{"
+    OpenBrace,
+    /// "}"
+    CloseBrace,
+    /// "["
+    OpenBracket,
+    /// "]"
+    CloseBracket,
+    /// "@"
+    At,
+    /// "#"
+    Pound,
+    /// "~"
+    Tilde,
+    /// "?"
+    Question,
+    /// ":"
+    Colon,
+    /// "$"
+    Dollar,
+    /// "="
+    Eq,
+    /// "!"
+    Bang,
+    /// "<"
+    Lt,
+    /// ">"
+    Gt,
+    /// "-"
+    Minus,
+    /// "&"
+    And,
+    /// "|"
+    Or,
+    /// "+"
+    Plus,
+    /// "*"
+    Star,
+    /// "/"
+    Slash,
+    /// "^"
+    Caret,
+    /// "%"
+    Percent,
+
+    // Two-char tokens:
+    /// "=="
+    EqEq,
+    /// "!="
+    BangEq,
+    /// "<="
+    Le,
+    /// ">="
+    Ge,
+    /// "<<"
+    Shl,
+    /// ">>"
+    Shr,
+    /// "&&"
+    AndAnd,
+    /// "||"
+    OrOr,
+    /// "->"
+    MinusGt,
+    /// "=>"
+    EqGt,
+
+    /// a character that doesn't start any recognized token
+    Unknown(char),
+
+    /// a token that failed to lex; the message describes why
+    Error(String),
+}
+
+fn scan(cur: &mut Cursor<impl Iterator<Item = Char>>) -> Option<Token> {
+    let char = cur.next()?;
+
+    let token_kind = match char.c {
+        '\t' | '\n' | '\r' | ' ' => {
+            cur.skip(|c| matches!(c, '\t' | '\n' | '\r' | ' '));
+            TokenKind::Whitespace
+        }
+        ';' => TokenKind::Semi,
+        ',' => TokenKind::Comma,
+        '.' => TokenKind::Dot,
+        '(' => TokenKind::OpenParen,
+        ')' => TokenKind::CloseParen,
+        '{' => TokenKind::OpenBrace,
+        '}' => TokenKind::CloseBrace,
+        '[' => TokenKind::OpenBracket,
+        ']' => TokenKind::CloseBracket,
+        '@' => TokenKind::At,
+        '#' => TokenKind::Pound,
+        '~' => TokenKind::Tilde,
+        '?' => TokenKind::Question,
+        ':' => TokenKind::Colon,
+        '$' => TokenKind::Dollar,
+        '=' => {
+            if cur.consume('=') {
+                TokenKind::EqEq
+            } else if cur.consume('>') {
+                TokenKind::EqGt
+            } else {
+                TokenKind::Eq
+            }
+        }
+        '!' => {
+            if cur.consume('=') {
+                TokenKind::BangEq
+            } else {
+                TokenKind::Bang
+            }
+        }
+        '<' => {
+            if cur.consume('=') {
+                TokenKind::Le
+            } else if cur.consume('<') {
+                TokenKind::Shl
+            } else {
+                TokenKind::Lt
+            }
+        }
+        '>' => {
+            if cur.consume('=') {
+                TokenKind::Ge
+            } else if cur.consume('>') {
+                TokenKind::Shr
+            } else {
+                TokenKind::Gt
+            }
+        }
+        '-' => {
+            if cur.consume('>') {
+                TokenKind::MinusGt
+            } else {
+                TokenKind::Minus
+            }
+        }
+        '&' => {
+            if cur.consume('&') {
+                TokenKind::AndAnd
+            } else {
+                TokenKind::And
+            }
+        }
+        '|' => {
+            if cur.consume('|') {
+                TokenKind::OrOr
+            } else {
+                TokenKind::Or
+            }
+        }
+        '+' => TokenKind::Plus,
+        '*' => TokenKind::Star,
+        '/' => {
+            if cur.consume('/') {
+                scan_line_comment(cur)
+            } else if cur.consume('*') {
+                scan_block_comment(cur)
+            } else {
+                TokenKind::Slash
+            }
+        }
+        '^' => TokenKind::Caret,
+        '%' => TokenKind::Percent,
+
+        _ => {
+            if char.c.is_ascii_alphabetic() {
+                scan_ident_or_reserved(cur, char)
+            } else if char.c.is_ascii_digit() {
+                scan_number(cur, char)
+            } else if char.c == '"' {
+                scan_literal(cur)
+            } else {
+                TokenKind::Unknown(char.c)
+            }
+        }
+    };
+
+    Some(Token {
+        token_kind,
+        line_num: char.line_num,
+        row_num: char.row_num,
+    })
+}
+
+fn scan_line_comment(cur: &mut Cursor<impl Iterator<Item = Char>>) -> TokenKind {
+    let doc = if cur.consume('/') {
+        Some(DocPlacement::Outer)
+    } else if cur.consume('!') {
+        Some(DocPlacement::Inner)
+    } else {
+        None
+    };
+
+    cur.skip(|c| c != '\n');
+
+    TokenKind::Comment {
+        shape: CommentShape::Line,
+        doc,
+    }
+}
+
+fn scan_block_comment(cur: &mut Cursor<impl Iterator<Item = Char>>) -> TokenKind {
+    let doc = match cur.first() {
+        Some('*') => Some(DocPlacement::Outer),
+        Some('!') => Some(DocPlacement::Inner),
+        _ => None,
+    };
+
+    let mut depth = 1u32;
+    loop {
+        match cur.next() {
+            Some(Char { c: '/', .. }) if cur.consume('*') => depth += 1,
+            Some(Char { c: '*', .. }) if cur.consume('/') => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            Some(_) => {}
+            None => return TokenKind::Error("unterminated block comment".to_string()),
+        }
+    }
+
+    TokenKind::Comment {
+        shape: CommentShape::Block,
+        doc,
+    }
+}
+
+fn scan_ident_or_reserved(cur: &mut Cursor<impl Iterator<Item = Char>>, first: Char) -> TokenKind {
+    let mut word = first.c.to_string();
+
+    cur.skip(|c| {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            word.push(c);
+            true
+        } else {
+            false
+        }
+    });
+
+    if let Some(reserved_kind) = detect_reserved(&word) {
+        TokenKind::Reserved(reserved_kind)
+    } else {
+        TokenKind::Ident(word)
+    }
+}
+
+fn scan_number(cur: &mut Cursor<impl Iterator<Item = Char>>, first: Char) -> TokenKind {
+    let mut digits = first.c.to_string();
+
+    cur.skip(|c| {
+        if c.is_ascii_digit() || c == '_' {
+            digits.push(c);
+            true
+        } else {
+            false
+        }
+    });
+
+    if cur.consume('\'') {
+        return scan_sized_literal(cur, &digits);
+    }
+
+    match digits.replace('_', "").parse() {
+        Ok(value) => TokenKind::Number(value),
+        Err(_) => TokenKind::Error(format!("number literal `{digits}` overflows u32")),
+    }
+}
+
+/// Scans the `'h` / `'d` tail of a width-prefixed literal like `8'hFF` or
+/// `8'd255`, after the `width` digits and the `'` have already been
+/// consumed, and lowers it into the same `TokenKind::Literal` the
+/// quoted `"_@"` binary literals produce.
+fn scan_sized_literal(cur: &mut Cursor<impl Iterator<Item = Char>>, width: &str) -> TokenKind {
+    let bitwidth: u32 = match width.replace('_', "").parse() {
+        Ok(bitwidth) => bitwidth,
+        Err(_) => return TokenKind::Error(format!("literal width `{width}` overflows u32")),
+    };
+
+    let radix: u32 = match cur.next() {
+        Some(Char { c: 'h', .. }) => 16,
+        Some(Char { c: 'd', .. }) => 10,
+        Some(other) => {
+            return TokenKind::Error(format!(
+                "unknown sized literal kind '{}', expected 'h' or 'd'",
+                other.c
+            ))
+        }
+        None => return TokenKind::Error("unterminated sized literal: missing 'h'/'d' kind".into()),
+    };
+
+    let mut digits = String::new();
+    cur.skip(|c| {
+        if c.is_digit(radix) || c == '_' {
+            digits.push(c);
+            true
+        } else {
+            false
+        }
+    });
+
+    let value = match u32::from_str_radix(&digits.replace('_', ""), radix) {
+        Ok(value) => value,
+        Err(_) => return TokenKind::Error(format!("sized literal `{digits}` overflows u32")),
+    };
+
+    let max = if bitwidth >= u32::BITS {
+        u32::MAX
+    } else {
+        (1u32 << bitwidth) - 1
+    };
+
+    if value > max {
+        return TokenKind::Error(format!("literal {value} does not fit in {bitwidth} bits"));
+    }
+
+    TokenKind::Literal {
+        bitwidth,
+        value,
+        mask: max,
+    }
+}
+
+/// Scans a quoted binary literal such as `"_@?"`, where `_` is a known 0
+/// bit, `@` is a known 1 bit, and `?` is a don't-care bit. Bits are shifted
+/// in most-significant-first, same as they're written, with `mask` tracking
+/// which bits are actually known (1) versus don't-care (0).
+fn scan_literal(cur: &mut Cursor<impl Iterator<Item = Char>>) -> TokenKind {
+    let mut value: u32 = 0;
+    let mut mask: u32 = 0;
+    let mut bitwidth: u32 = 0;
+
+    loop {
+        match cur.first() {
+            Some('_') => {
+                cur.next();
+                value <<= 1;
+                mask = (mask << 1) | 1;
+                bitwidth += 1;
+            }
+            Some('@') => {
+                cur.next();
+                value = (value << 1) | 1;
+                mask = (mask << 1) | 1;
+                bitwidth += 1;
+            }
+            Some('?') => {
+                cur.next();
+                value <<= 1;
+                mask <<= 1;
+                bitwidth += 1;
+            }
+            Some('"') => {
+                cur.next();
+                break;
+            }
+            Some(_) | None => {
+                return TokenKind::Error("unterminated literal: missing closing '\"'".to_string());
+            }
+        }
+    }
+
+    if bitwidth > u32::BITS {
+        return TokenKind::Error(format!("literal of {bitwidth} bits overflows u32"));
+    }
+
+    TokenKind::Literal {
+        bitwidth,
+        value,
+        mask,
+    }
+}
+
+/// Lexes `src` into a stream of [`Token`]s, in the spirit of `rustc_lexer`:
+/// pure lexing over source text, with no IO or reporting baked in.
+pub fn tokenize(src: &str) -> impl Iterator<Item = Token> + '_ {
+    let mut cur = cursor(src);
+    std::iter::from_fn(move || scan(&mut cur))
+}
@@ -1,11 +1,11 @@
-use crate::Char;
+use super::Char;
 use std::iter::Peekable;
 
 pub struct Cursor<T: Iterator<Item = Char>> {
     iter: Peekable<T>,
 }
 
-pub fn cursor(source: String) -> Cursor<impl Iterator<Item = Char>> {
+pub fn cursor(source: &str) -> Cursor<impl Iterator<Item = Char>> {
     let iter = source
         .lines()
         .map(|line| line.to_owned())
@@ -36,6 +36,11 @@ impl<T: Iterator<Item = Char>> Cursor<T> {
         self.iter.next_if(|char| char.c == c).is_some()
     }
 
+    /// Peeks the next char without consuming it.
+    pub fn first(&mut self) -> Option<char> {
+        self.iter.peek().map(|char| char.c)
+    }
+
     pub fn skip(&mut self, mut predicate: impl FnMut(char) -> bool) {
         while {
             if let Some(char) = self.iter.peek() {